@@ -1,12 +1,15 @@
 use std::{env, fs};
+use std::f32::consts::TAU;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 use clap::{Parser};
+use rand::Rng;
 use winit::{
     dpi::LogicalSize,
-    event::{Event},
+    event::{Event, WindowEvent},
     event_loop::{ControlFlow, EventLoop},
-    window::{Fullscreen, WindowBuilder},
+    monitor::MonitorHandle,
+    window::{Fullscreen, Window, WindowBuilder},
 };
 use pixels::{Pixels, SurfaceTexture};
 use serde::{Deserialize, Serialize};
@@ -18,6 +21,12 @@ struct Config {
     interval: Option<u64>,
     duration: Option<u64>,
     flash_interval: Option<u64>,
+    monitors: Option<String>,
+    mode: Option<String>,
+    fade: Option<f64>,
+    reminder_style: Option<String>,
+    blink_count: Option<u32>,
+    image: Option<String>,
 }
 
 impl Default for Config {
@@ -26,6 +35,377 @@ impl Default for Config {
             interval: Some(1200),
             duration: Some(60),
             flash_interval: Some(1000),
+            monitors: Some("all".to_string()),
+            mode: Some("solid".to_string()),
+            fade: Some(1.0),
+            reminder_style: Some("fullscreen".to_string()),
+            blink_count: Some(5),
+            image: None,
+        }
+    }
+}
+
+/// 提醒的呈现方式
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ReminderStyle {
+    /// 独占全屏 + 置顶，原有行为
+    Fullscreen,
+    /// 不抢占焦点，仅闪烁任务栏图标提示
+    Attention,
+}
+
+impl ReminderStyle {
+    fn from_str(s: &str) -> Self {
+        match s {
+            "attention" => ReminderStyle::Attention,
+            _ => ReminderStyle::Fullscreen,
+        }
+    }
+}
+
+/// 让窗口的任务栏图标闪烁 `blink_count` 次，每次间隔 `flash_interval`；
+/// 使用 `FLASHW_TIMERNOFG`，窗口获得焦点后系统会自动停止闪烁
+#[cfg(target_os = "windows")]
+fn flash_window_attention(window: &Window, blink_count: u32, flash_interval: Duration) {
+    use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+    use winapi::shared::windef::HWND;
+    use winapi::um::winuser::{FlashWindowEx, FLASHWINFO, FLASHW_ALL, FLASHW_TIMERNOFG};
+
+    let hwnd = match window.raw_window_handle() {
+        RawWindowHandle::Win32(handle) => handle.hwnd as HWND,
+        _ => return,
+    };
+
+    let mut info = FLASHWINFO {
+        cbSize: std::mem::size_of::<FLASHWINFO>() as u32,
+        hwnd,
+        dwFlags: FLASHW_ALL | FLASHW_TIMERNOFG,
+        uCount: blink_count,
+        dwTimeout: flash_interval.as_millis() as u32,
+    };
+
+    unsafe {
+        FlashWindowEx(&mut info);
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn flash_window_attention(_window: &Window, _blink_count: u32, _flash_interval: Duration) {
+    // FlashWindowEx 是 Windows 专属 API，其他平台上 "attention" 样式暂时退化为无操作
+    eprintln!("提醒样式 \"attention\" 目前仅支持 Windows");
+}
+
+/// 提前停止任务栏闪烁，用于窗口重新获得焦点时
+#[cfg(target_os = "windows")]
+fn stop_flashing(window: &Window) {
+    use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+    use winapi::shared::windef::HWND;
+    use winapi::um::winuser::{FlashWindowEx, FLASHWINFO, FLASHW_STOP};
+
+    let hwnd = match window.raw_window_handle() {
+        RawWindowHandle::Win32(handle) => handle.hwnd as HWND,
+        _ => return,
+    };
+
+    let mut info = FLASHWINFO {
+        cbSize: std::mem::size_of::<FLASHWINFO>() as u32,
+        hwnd,
+        dwFlags: FLASHW_STOP,
+        uCount: 0,
+        dwTimeout: 0,
+    };
+
+    unsafe {
+        FlashWindowEx(&mut info);
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn stop_flashing(_window: &Window) {}
+
+/// 提醒画面的渲染方式
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RenderMode {
+    /// 纯色全屏填充（默认，原有行为）
+    Solid,
+    /// 从屏幕中心喷发的粒子爆炸效果
+    Particles,
+    /// 向外飘散、不受重力影响的星空效果
+    Stars,
+}
+
+impl RenderMode {
+    fn from_str(s: &str) -> Self {
+        match s {
+            "particles" => RenderMode::Particles,
+            "stars" => RenderMode::Stars,
+            _ => RenderMode::Solid,
+        }
+    }
+}
+
+/// 一个动画粒子，用于 particles/stars 渲染模式
+#[derive(Clone, Copy)]
+struct Particle {
+    active: bool,
+    life: f32,
+    fade: f32,
+    r: u8,
+    g: u8,
+    b: u8,
+    x: f32,
+    y: f32,
+    vx: f32,
+    vy: f32,
+    gx: f32,
+    gy: f32,
+}
+
+impl Particle {
+    /// 生成一个新粒子：从屏幕中心出发，随机方向、随机速度，颜色取自 `colors`
+    fn spawn(rng: &mut impl Rng, center_x: f32, center_y: f32, colors: &[[u8; 4]], mode: RenderMode) -> Self {
+        let speed = rng.gen_range(1.0..4.0);
+        let angle = rng.gen_range(0.0..TAU);
+        let color = colors[rng.gen_range(0..colors.len())];
+        // 粒子模式会受重力下坠，星空模式保持匀速飘散
+        let gy = match mode {
+            RenderMode::Particles => 0.05,
+            _ => 0.0,
+        };
+
+        Particle {
+            active: true,
+            life: 1.0,
+            fade: rng.gen_range(0.004..0.015),
+            r: color[0],
+            g: color[1],
+            b: color[2],
+            x: center_x,
+            y: center_y,
+            vx: speed * angle.cos(),
+            vy: speed * angle.sin(),
+            gx: 0.0,
+            gy,
+        }
+    }
+}
+
+/// 按 `t`（0.0~1.0）在 `prev` 和 `next` 两个 RGBA 颜色之间线性插值
+fn lerp_color(prev: [u8; 4], next: [u8; 4], t: f32) -> [u8; 4] {
+    let t = t.clamp(0.0, 1.0);
+    let mut blended = [0u8; 4];
+    for i in 0..4 {
+        blended[i] = (prev[i] as f32 * (1.0 - t) + next[i] as f32 * t) as u8;
+    }
+    blended
+}
+
+/// 解码后的背景图片，统一存成自顶向下、逐行排列的 RGBA8 数据
+struct DecodedImage {
+    width: u32,
+    height: u32,
+    rgba: Vec<u8>,
+}
+
+/// 加载提醒时展示的背景图片；TGA 走手写解析，其余格式交给 `image` crate 解码。
+/// 任何失败都只打印警告并返回 `None`，调用方据此回退到纯色循环
+fn load_background_image(path: &Path) -> Option<DecodedImage> {
+    let is_tga = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("tga"))
+        .unwrap_or(false);
+
+    let result = if is_tga {
+        load_tga(path)
+    } else {
+        load_with_image_crate(path)
+    };
+
+    match result {
+        Ok(image) => Some(image),
+        Err(e) => {
+            eprintln!("加载背景图片 {:?} 失败，将回退到纯色循环：{:?}", path, e);
+            None
+        }
+    }
+}
+
+fn load_with_image_crate(path: &Path) -> Result<DecodedImage> {
+    let img = image::open(path).with_context(|| format!("Failed to decode image: {:?}", path))?;
+    let rgba = img.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    Ok(DecodedImage {
+        width,
+        height,
+        rgba: rgba.into_raw(),
+    })
+}
+
+/// 手动解析 24/32 位 TGA：支持未压缩真彩色（类型 2）与 RLE 压缩真彩色（类型 10），
+/// 并根据图像描述符字节的 bit 5 处理自底向上/自顶向下两种原点约定
+fn load_tga(path: &Path) -> Result<DecodedImage> {
+    let data = fs::read(path).with_context(|| format!("Failed to read TGA file: {:?}", path))?;
+    if data.len() < 18 {
+        anyhow::bail!("TGA 文件头不完整: {:?}", path);
+    }
+
+    let id_length = data[0] as usize;
+    let image_type = data[2];
+    let width = u16::from_le_bytes([data[12], data[13]]) as usize;
+    let height = u16::from_le_bytes([data[14], data[15]]) as usize;
+    let bpp = data[16];
+    let top_origin = data[17] & 0x20 != 0;
+
+    if width == 0 || height == 0 {
+        anyhow::bail!("TGA 图像尺寸为 0（{}x{}）: {:?}", width, height, path);
+    }
+
+    if bpp != 24 && bpp != 32 {
+        anyhow::bail!("仅支持 24/32 位 TGA，当前为 {} 位: {:?}", bpp, path);
+    }
+
+    let bytes_per_pixel = (bpp / 8) as usize;
+    let pixel_count = width * height;
+    let mut raw_pixels = vec![0u8; pixel_count * bytes_per_pixel];
+    let mut cursor = 18 + id_length;
+
+    match image_type {
+        2 => {
+            // 未压缩真彩色：像素数据连续存放
+            let needed = raw_pixels.len();
+            if data.len() < cursor + needed {
+                anyhow::bail!("TGA 像素数据不完整: {:?}", path);
+            }
+            raw_pixels.copy_from_slice(&data[cursor..cursor + needed]);
+        }
+        10 => {
+            // RLE 压缩真彩色：每个数据包以 1 字节包头开始
+            let mut out_index = 0;
+            while out_index < raw_pixels.len() {
+                let packet_header = *data
+                    .get(cursor)
+                    .with_context(|| format!("TGA RLE 数据提前结束: {:?}", path))?;
+                cursor += 1;
+                let count = (packet_header & 0x7F) as usize + 1;
+
+                if packet_header & 0x80 != 0 {
+                    // 行程包：同一个像素重复 count 次
+                    let pixel = data
+                        .get(cursor..cursor + bytes_per_pixel)
+                        .with_context(|| format!("TGA RLE 数据提前结束: {:?}", path))?;
+                    cursor += bytes_per_pixel;
+                    for _ in 0..count {
+                        let dst = raw_pixels
+                            .get_mut(out_index..out_index + bytes_per_pixel)
+                            .with_context(|| format!("TGA RLE 数据超出图像尺寸: {:?}", path))?;
+                        dst.copy_from_slice(pixel);
+                        out_index += bytes_per_pixel;
+                    }
+                } else {
+                    // 原始包：count 个互不相同的像素
+                    let needed = count * bytes_per_pixel;
+                    let chunk = data
+                        .get(cursor..cursor + needed)
+                        .with_context(|| format!("TGA RLE 数据提前结束: {:?}", path))?;
+                    let dst = raw_pixels
+                        .get_mut(out_index..out_index + needed)
+                        .with_context(|| format!("TGA RLE 数据超出图像尺寸: {:?}", path))?;
+                    dst.copy_from_slice(chunk);
+                    cursor += needed;
+                    out_index += needed;
+                }
+            }
+        }
+        other => anyhow::bail!("不支持的 TGA 图像类型: {} ({:?})", other, path),
+    }
+
+    // TGA 按 BGR(A) 存储像素，默认原点在左下角；统一转换为自顶向下的 RGBA
+    let mut rgba = vec![0u8; pixel_count * 4];
+    for y in 0..height {
+        let src_row = if top_origin { y } else { height - 1 - y };
+        for x in 0..width {
+            let src_idx = (src_row * width + x) * bytes_per_pixel;
+            let dst_idx = (y * width + x) * 4;
+            rgba[dst_idx] = raw_pixels[src_idx + 2]; // R
+            rgba[dst_idx + 1] = raw_pixels[src_idx + 1]; // G
+            rgba[dst_idx + 2] = raw_pixels[src_idx]; // B
+            rgba[dst_idx + 3] = if bytes_per_pixel == 4 { raw_pixels[src_idx + 3] } else { 0xFF };
+        }
+    }
+
+    Ok(DecodedImage {
+        width: width as u32,
+        height: height as u32,
+        rgba,
+    })
+}
+
+/// 将解码后的图片用最近邻采样缩放，直接铺满整个 frame 缓冲区
+fn blit_image_scaled(frame: &mut [u8], dst_width: u32, dst_height: u32, image: &DecodedImage) {
+    for y in 0..dst_height {
+        let src_y = (y as u64 * image.height as u64 / dst_height.max(1) as u64) as u32;
+        for x in 0..dst_width {
+            let src_x = (x as u64 * image.width as u64 / dst_width.max(1) as u64) as u32;
+            let src_idx = ((src_y * image.width + src_x) * 4) as usize;
+            let dst_idx = ((y * dst_width + x) * 4) as usize;
+            frame[dst_idx..dst_idx + 4].copy_from_slice(&image.rgba[src_idx..src_idx + 4]);
+        }
+    }
+}
+
+const PARTICLE_COUNT: usize = 150;
+/// `x += vx / PARTICLE_SLOWDOWN`，让粒子运动速度更贴近真实的爆炸/漂浮观感
+const PARTICLE_SLOWDOWN: f32 = 4.0;
+
+/// 推进所有粒子一帧：衰减生命值、施加速度与重力，死亡的粒子在屏幕中心重生
+fn update_particles(particles: &mut [Particle], center_x: f32, center_y: f32, colors: &[[u8; 4]], mode: RenderMode) {
+    let mut rng = rand::thread_rng();
+    for particle in particles.iter_mut() {
+        if !particle.active || particle.life <= 0.0 {
+            *particle = Particle::spawn(&mut rng, center_x, center_y, colors, mode);
+            continue;
+        }
+
+        particle.life -= particle.fade;
+        particle.x += particle.vx / PARTICLE_SLOWDOWN;
+        particle.y += particle.vy / PARTICLE_SLOWDOWN;
+        particle.vx += particle.gx;
+        particle.vy += particle.gy;
+    }
+}
+
+/// 将所有存活的粒子光栅化为带透明混合的小方块，画在黑色背景之上
+fn render_particles(frame: &mut [u8], width: u32, height: u32, particles: &[Particle]) {
+    for chunk in frame.chunks_exact_mut(4) {
+        chunk.copy_from_slice(&[0x00, 0x00, 0x00, 0xFF]);
+    }
+
+    const HALF_SIZE: i32 = 3;
+    for particle in particles {
+        if !particle.active || particle.life <= 0.0 {
+            continue;
+        }
+
+        let alpha = particle.life.clamp(0.0, 1.0);
+        let cx = particle.x as i32;
+        let cy = particle.y as i32;
+
+        for dy in -HALF_SIZE..=HALF_SIZE {
+            for dx in -HALF_SIZE..=HALF_SIZE {
+                let x = cx + dx;
+                let y = cy + dy;
+                if x < 0 || y < 0 || x as u32 >= width || y as u32 >= height {
+                    continue;
+                }
+
+                let idx = ((y as u32 * width + x as u32) * 4) as usize;
+                let dst = &mut frame[idx..idx + 4];
+                dst[0] = (particle.r as f32 * alpha + dst[0] as f32 * (1.0 - alpha)) as u8;
+                dst[1] = (particle.g as f32 * alpha + dst[1] as f32 * (1.0 - alpha)) as u8;
+                dst[2] = (particle.b as f32 * alpha + dst[2] as f32 * (1.0 - alpha)) as u8;
+                dst[3] = 0xFF;
+            }
         }
     }
 }
@@ -49,13 +429,19 @@ config.toml\n\n\
 interval=60\n\
 duration=10\n\
 flash_interval=1000\n\
+monitors=\"all\"\n\
+mode=\"particles\"\n\
+fade=0.2\n\
+reminder_style=\"attention\"\n\
+blink_count=5\n\
+image=\"background.png\"\n\
 ```\n\n\
 配置文件可以和程序同一个目录，也可以通过参数`-c`指定配置文件的绝对路径\n\n\
 ## 控制台参数\n\n\
 自定义参数:\n\n\
 ```sh\n\
 eye_care_rs.exe -i 60 -d 10 -f 800
-```
+```\n\
 或者\n\n\
 ```sh\n\
 eye_care_rs.exe --interval 60 --duration 10 --flash-interval 800
@@ -76,6 +462,30 @@ struct Opt {
     /// 颜色切换间隔（毫秒）
     #[clap(short = 'f', long)]
     flash_interval: Option<u64>,
+
+    /// 提醒覆盖的显示器："all" 覆盖所有显示器，"primary" 仅覆盖主显示器
+    #[clap(short, long)]
+    monitors: Option<String>,
+
+    /// 提醒画面的渲染方式："solid" 纯色闪烁，"particles" 粒子爆炸，"stars" 星空飘散
+    #[clap(short = 'M', long)]
+    mode: Option<String>,
+
+    /// 纯色模式下每帧叠加的混合系数（0.0~1.0），小于 1.0 时画面会呈现渐变脉冲而非生硬闪烁
+    #[clap(short = 'a', long)]
+    fade: Option<f64>,
+
+    /// 提醒呈现方式："fullscreen" 独占全屏提醒（默认），"attention" 仅闪烁任务栏图标
+    #[clap(short = 'r', long)]
+    reminder_style: Option<String>,
+
+    /// attention 样式下任务栏图标的闪烁次数
+    #[clap(short = 'b', long)]
+    blink_count: Option<u32>,
+
+    /// 提醒时显示的背景图片路径（支持 TGA / PNG），与纯色循环交替显示
+    #[clap(short = 'g', long, value_name = "FILE")]
+    image: Option<PathBuf>,
 }
 fn read_config<P: AsRef<Path>>(path: P) -> Result<Config> {
     let path = path.as_ref();
@@ -116,6 +526,24 @@ fn main() -> Result<()>{
     if let Some(flash_interval) = args.flash_interval {
         config.flash_interval = Some(flash_interval);
     }
+    if let Some(monitors) = args.monitors {
+        config.monitors = Some(monitors);
+    }
+    if let Some(mode) = args.mode {
+        config.mode = Some(mode);
+    }
+    if let Some(fade) = args.fade {
+        config.fade = Some(fade);
+    }
+    if let Some(reminder_style) = args.reminder_style {
+        config.reminder_style = Some(reminder_style);
+    }
+    if let Some(blink_count) = args.blink_count {
+        config.blink_count = Some(blink_count);
+    }
+    if let Some(image) = args.image {
+        config.image = Some(image.to_string_lossy().to_string());
+    }
 
     // 在控制台输出参数值
     println!("Config file: {:?}", config_path);
@@ -123,11 +551,27 @@ fn main() -> Result<()>{
     println!("提醒间隔（秒）：{}", config.interval.unwrap_or(1200));
     println!("提醒持续时间（秒）：{}", config.duration.unwrap_or(60));
     println!("颜色切换间隔（毫秒）：{}", config.flash_interval.unwrap_or(1000));
+    println!("覆盖显示器：{}", config.monitors.as_deref().unwrap_or("all"));
+    println!("渲染模式：{}", config.mode.as_deref().unwrap_or("solid"));
+    println!("混合系数：{}", config.fade.unwrap_or(1.0));
+    println!("提醒样式：{}", config.reminder_style.as_deref().unwrap_or("fullscreen"));
+    println!("任务栏闪烁次数：{}", config.blink_count.unwrap_or(5));
+    println!("背景图片：{}", config.image.as_deref().unwrap_or("无（使用纯色循环）"));
 
     // 定义定时器参数，使用命令行参数的值
     let reminder_interval = Duration::from_secs(config.interval.unwrap_or(1200));
     let reminder_duration = Duration::from_secs(config.duration.unwrap_or(60));
     let switch_interval = Duration::from_millis(config.flash_interval.unwrap_or(1000));
+    let cover_all_monitors = config.monitors.as_deref().unwrap_or("all") != "primary";
+    let render_mode = RenderMode::from_str(config.mode.as_deref().unwrap_or("solid"));
+    let reminder_style = ReminderStyle::from_str(config.reminder_style.as_deref().unwrap_or("fullscreen"));
+    let blink_count = config.blink_count.unwrap_or(5);
+    // 未配置图片，或解码失败时，都回退到原有的纯色循环
+    let background_image = config.image.as_ref().and_then(|path| load_background_image(Path::new(path)));
+    // 混合系数：1.0 表示直接覆盖（原有行为），小于 1.0 时新旧画面按 alpha 混合，形成柔和脉冲
+    let fade_alpha = config.fade.unwrap_or(1.0).clamp(0.0, 1.0) as f32;
+    // particles/stars 模式下每帧都要重绘，维持动画的刷新节奏
+    let frame_tick = Duration::from_millis(16);
 
     // https://doodlewind.github.io/learn-wgpu-cn/beginner/tutorial1-window/#%E4%BD%BF%E7%94%A8-rust-%E7%9A%84%E6%96%B0%E7%89%88%E7%89%B9%E6%80%A7%E8%A7%A3%E6%9E%90%E5%99%A8
     // 通过 env_logger::init() 来启用日志是非常重要的。当 wgpu 遇到各类错误时，它都会用一条通用性的消息抛出 panic，并通过日志 crate 来记录真正的错误信息。这意味着如果不添加 env_logger::init()，wgpu 将静默地退出，从而使你非常困惑！
@@ -136,18 +580,37 @@ fn main() -> Result<()>{
     // 创建事件循环
     let event_loop = EventLoop::new();
 
-    // 创建一个隐藏的窗口，初始为不可见
-    let window = WindowBuilder::new()
-        .with_title("护眼提醒")
-        .with_decorations(false)  // 无边框窗口
-        .with_inner_size(LogicalSize::new(800, 600))
-        .with_visible(false)
-        .build(&event_loop)
-        .unwrap();
+    // 枚举需要覆盖的显示器：多屏模式下每个显示器都需要一个全屏窗口，
+    // 这样闪烁提醒才不会漏掉任何一块屏幕
+    let target_monitors: Vec<MonitorHandle> = if cover_all_monitors {
+        let monitors: Vec<MonitorHandle> = event_loop.available_monitors().collect();
+        if monitors.is_empty() {
+            event_loop.primary_monitor().into_iter().collect()
+        } else {
+            monitors
+        }
+    } else {
+        event_loop.primary_monitor().into_iter().collect()
+    };
+
+    // 为每块目标显示器创建一个隐藏的窗口，初始为不可见
+    let windows: Vec<Window> = target_monitors
+        .iter()
+        .map(|_| {
+            WindowBuilder::new()
+                .with_title("护眼提醒")
+                .with_decorations(false) // 无边框窗口
+                .with_inner_size(LogicalSize::new(800, 600))
+                .with_visible(false)
+                .build(&event_loop)
+                .unwrap()
+        })
+        .collect();
 
     let mut last_reminder = Instant::now();
     let mut reminder_start = None;
-    let mut last_switch = Instant::now();
+    let mut last_switch = Instant::now(); // 纯色模式下上一次切换目标颜色的时间点
+    let mut last_frame = Instant::now(); // 上一次请求重绘的时间点，用于驱动逐帧动画
     let mut color_index = 0; // 用于循环颜色的索引
 
     // 定义颜色数组
@@ -162,8 +625,33 @@ fn main() -> Result<()>{
         [0xFF, 0x00, 0x00, 0xFF], // 红色
     ];
 
-    // 将 pixels 声明为可选的
-    let mut pixels: Option<Pixels> = None;
+    // 将每个窗口对应的 pixels 声明为可选的，按窗口顺序一一对应
+    let mut pixels_list: Vec<Option<Pixels>> = windows.iter().map(|_| None).collect();
+
+    // particles/stars 模式下每个窗口各自持有一份粒子池（而非全局共享），
+    // 这样多屏下分辨率不同的显示器也能各自以自己的中心喷发，不会偏移或被裁剪
+    let mut particle_pools: Vec<Vec<Particle>> = windows
+        .iter()
+        .map(|_| {
+            vec![
+                Particle {
+                    active: true,
+                    life: 0.0,
+                    fade: 0.01,
+                    r: 0,
+                    g: 0,
+                    b: 0,
+                    x: 0.0,
+                    y: 0.0,
+                    vx: 0.0,
+                    vy: 0.0,
+                    gx: 0.0,
+                    gy: 0.0,
+                };
+                PARTICLE_COUNT
+            ]
+        })
+        .collect();
 
     event_loop.run(move |event, _, control_flow| {
         match event {
@@ -174,54 +662,119 @@ fn main() -> Result<()>{
                 if reminder_start.is_none() && now - last_reminder >= reminder_interval {
                     // 开始提醒
                     reminder_start = Some(now);
-                    // 设置窗口为置顶
-                    window.set_always_on_top(true);
-                    // 进入全屏模式
-                    window.set_fullscreen(Some(Fullscreen::Borderless(None)));
-                    window.set_visible(true);
-
-                    // 仅在第一次需要显示提醒时初始化 pixels
-                    if pixels.is_none() {
-                        let window_size = window.inner_size();
-                        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
-                        pixels = Some(Pixels::new(window_size.width, window_size.height, surface_texture).unwrap());
-                    }
 
-                    // 请求立即重绘
-                    window.request_redraw();
+                    match reminder_style {
+                        ReminderStyle::Fullscreen => {
+                            // 让每个窗口在各自的显示器上进入全屏，保证多屏同时被覆盖
+                            for (window, monitor) in windows.iter().zip(target_monitors.iter()) {
+                                // 设置窗口为置顶
+                                window.set_always_on_top(true);
+                                // 进入全屏模式（绑定到对应的显示器）
+                                window.set_fullscreen(Some(Fullscreen::Borderless(Some(monitor.clone()))));
+                                window.set_visible(true);
+                            }
+
+                            // 仅在第一次需要显示提醒时初始化 pixels
+                            for (window, pixels) in windows.iter().zip(pixels_list.iter_mut()) {
+                                if pixels.is_none() {
+                                    let window_size = window.inner_size();
+                                    let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, window);
+                                    *pixels = Some(Pixels::new(window_size.width, window_size.height, surface_texture).unwrap());
+                                }
+                            }
+
+                            // 请求立即重绘
+                            for window in &windows {
+                                window.request_redraw();
+                            }
+                        }
+                        ReminderStyle::Attention => {
+                            // 显示窗口使其任务栏图标出现，但立即最小化，
+                            // 这样桌面上不会冒出一个未渲染的空白矩形——只有任务栏条目会闪烁
+                            for window in &windows {
+                                window.set_visible(true);
+                                window.set_minimized(true);
+                                flash_window_attention(window, blink_count, switch_interval);
+                            }
+                        }
+                    }
                 }
 
                 // 如果正在提醒
                 if let Some(start_time) = reminder_start {
-                    // 计算下一次颜色切换时间
-                    let next_switch = last_switch + switch_interval;
                     let next_end = start_time + reminder_duration;
                     let now = Instant::now();
 
-                    if now >= next_switch {
-                        last_switch = now;
-                        // 更新颜色索引
-                        color_index = (color_index + 1) % colors.len();
-                        // 请求重绘
-                        window.request_redraw();
-                    }
+                    match reminder_style {
+                        ReminderStyle::Fullscreen => {
+                            // 所有模式都以 frame_tick 为节奏逐帧重绘：
+                            // 纯色模式借此在 switch_interval 窗口内做渐变过渡，粒子/星空模式借此驱动动画
+                            let next_frame = last_frame + frame_tick;
+                            let next_switch = last_switch + switch_interval;
+
+                            if now >= next_frame {
+                                last_frame = now;
+                                match render_mode {
+                                    RenderMode::Solid => {
+                                        // 到达切换边界时才推进颜色索引，期间的每一帧都在插值
+                                        if now >= next_switch {
+                                            last_switch = now;
+                                            color_index = (color_index + 1) % colors.len();
+                                        }
+                                    }
+                                    RenderMode::Particles | RenderMode::Stars => {
+                                        // 每个窗口用自己的尺寸计算喷发中心，各显示器的粒子互不影响
+                                        for (window, pool) in windows.iter().zip(particle_pools.iter_mut()) {
+                                            let center = window.inner_size();
+                                            update_particles(
+                                                pool,
+                                                center.width as f32 / 2.0,
+                                                center.height as f32 / 2.0,
+                                                &colors,
+                                                render_mode,
+                                            );
+                                        }
+                                    }
+                                }
+                                // 请求重绘，所有显示器同步切换画面
+                                for window in &windows {
+                                    window.request_redraw();
+                                }
+                            }
 
-                    if now >= next_end {
-                        // 提醒结束
-                        reminder_start = None;
-                        last_reminder = now;
-                        window.set_visible(false);
-                        // 退出全屏模式
-                        window.set_fullscreen(None);
-                        // 取消置顶
-                        window.set_always_on_top(false);
-
-                        // 销毁 pixels 对象
-                        // pixels = None;
-                    } else {
-                        // 设置下一次事件触发时间，节省 CPU
-                        let next_event = std::cmp::min(next_switch, next_end);
-                        *control_flow = ControlFlow::WaitUntil(next_event);
+                            if now >= next_end {
+                                // 提醒结束
+                                reminder_start = None;
+                                last_reminder = now;
+                                for window in &windows {
+                                    window.set_visible(false);
+                                    // 退出全屏模式
+                                    window.set_fullscreen(None);
+                                    // 取消置顶
+                                    window.set_always_on_top(false);
+                                }
+
+                                // 销毁 pixels 对象
+                                // pixels_list.iter_mut().for_each(|p| *p = None);
+                            } else {
+                                // 设置下一次事件触发时间，节省 CPU
+                                let next_event = std::cmp::min(next_frame, next_end);
+                                *control_flow = ControlFlow::WaitUntil(next_event);
+                            }
+                        }
+                        ReminderStyle::Attention => {
+                            if now >= next_end {
+                                // 提醒结束：停止任务栏闪烁（包括闪完 blink_count 次后仍保留的高亮），并隐藏窗口
+                                reminder_start = None;
+                                last_reminder = now;
+                                for window in &windows {
+                                    stop_flashing(window);
+                                    window.set_visible(false);
+                                }
+                            } else {
+                                *control_flow = ControlFlow::WaitUntil(next_end);
+                            }
+                        }
                     }
                 } else {
                     // 设置下一次提醒的事件触发时间
@@ -230,42 +783,86 @@ fn main() -> Result<()>{
                 }
             }
 
-            Event::RedrawRequested(_) => {
-                if let Some(pixels) = &mut pixels {
-                    // 执行渲染操作
-                    let frame = pixels.get_frame_mut();
-                    let color = colors[color_index];
-                    for chunk in frame.chunks_exact_mut(4) {
-                        chunk.copy_from_slice(&color);
+            Event::WindowEvent { event, window_id } => {
+                // 处理窗口事件，例如窗口大小变化、获得焦点
+                if let Some(idx) = windows.iter().position(|w| w.id() == window_id) {
+                    match event {
+                        WindowEvent::Resized(size) => {
+                            // 分辨率/投影仪切换等原因导致表面尺寸变化时，同步重建 Pixels 缓冲区
+                            if let Some(pixels) = &mut pixels_list[idx] {
+                                if let Err(e) = pixels.resize_surface(size.width, size.height) {
+                                    eprintln!("Failed to resize surface: {:?}", e);
+                                    *control_flow = ControlFlow::Exit;
+                                }
+                                if let Err(e) = pixels.resize_buffer(size.width, size.height) {
+                                    eprintln!("Failed to resize buffer: {:?}", e);
+                                    *control_flow = ControlFlow::Exit;
+                                }
+                            }
+                        }
+                        WindowEvent::Focused(true) => {
+                            // 窗口重新获得焦点时，提前停止任务栏闪烁；
+                            // attention 样式下窗口本身也没有内容可看，一并隐藏
+                            stop_flashing(&windows[idx]);
+                            if reminder_style == ReminderStyle::Attention {
+                                windows[idx].set_visible(false);
+                            }
+                        }
+                        _ => {}
                     }
+                }
+            }
+
+            Event::RedrawRequested(window_id) => {
+                if let Some(idx) = windows.iter().position(|w| w.id() == window_id) {
+                    if let Some(pixels) = &mut pixels_list[idx] {
+                        // 执行渲染操作
+                        let size = windows[idx].inner_size();
+                        let frame = pixels.get_frame_mut();
+                        match render_mode {
+                            RenderMode::Solid => {
+                                // 配置了背景图片时，在图片与纯色之间按 switch_interval 交替显示
+                                let show_image = background_image.is_some() && color_index % 2 == 0;
 
-                    if let Err(e) = pixels.render() {
-                        eprintln!("pixels.render() failed: {:?}", e);
-                        *control_flow = ControlFlow::Exit;
+                                if let (true, Some(image)) = (show_image, &background_image) {
+                                    blit_image_scaled(frame, size.width, size.height, image);
+                                } else {
+                                    // 按 t 在上一个颜色和下一个颜色之间线性插值，避免生硬跳变
+                                    let prev_index = (color_index + colors.len() - 1) % colors.len();
+                                    let t = last_switch.elapsed().as_secs_f32()
+                                        / switch_interval.as_secs_f32().max(f32::EPSILON);
+                                    let color = lerp_color(colors[prev_index], colors[color_index], t);
+
+                                    for chunk in frame.chunks_exact_mut(4) {
+                                        if fade_alpha >= 1.0 {
+                                            chunk.copy_from_slice(&color);
+                                        } else {
+                                            // 与上一帧的画面混合，形成柔和脉冲而非生硬覆盖
+                                            chunk[0] = (color[0] as f32 * fade_alpha
+                                                + chunk[0] as f32 * (1.0 - fade_alpha)) as u8;
+                                            chunk[1] = (color[1] as f32 * fade_alpha
+                                                + chunk[1] as f32 * (1.0 - fade_alpha)) as u8;
+                                            chunk[2] = (color[2] as f32 * fade_alpha
+                                                + chunk[2] as f32 * (1.0 - fade_alpha)) as u8;
+                                            chunk[3] = 0xFF;
+                                        }
+                                    }
+                                }
+                            }
+                            RenderMode::Particles | RenderMode::Stars => {
+                                render_particles(frame, size.width, size.height, &particle_pools[idx]);
+                            }
+                        }
+
+                        if let Err(e) = pixels.render() {
+                            eprintln!("pixels.render() failed: {:?}", e);
+                            *control_flow = ControlFlow::Exit;
+                        }
                     }
                 }
             }
 
-            // Event::WindowEvent { event, .. } => {
-            //     // 处理窗口事件，例如窗口大小变化
-            //     if let Some(pixels) = &mut pixels {
-            //         match event {
-            //             WindowEvent::Resized(size) => {
-            //                 if let Err(e) = pixels.resize_surface(size.width, size.height) {
-            //                     eprintln!("Failed to resize surface: {:?}", e);
-            //                     *control_flow = ControlFlow::Exit;
-            //                 }
-            //                 if let Err(e) = pixels.resize_buffer(size.width, size.height) {
-            //                     eprintln!("Failed to resize buffer: {:?}", e);
-            //                     *control_flow = ControlFlow::Exit;
-            //                 }
-            //             }
-            //             _ => {}
-            //         }
-            //     }
-            // }
-
             _ => {}
         }
     });
-}
\ No newline at end of file
+}